@@ -1,8 +1,10 @@
 //! HTTP Cookies
 
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt;
 use std::future::Future;
+use std::io::{BufRead, Write};
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::time::SystemTime;
@@ -13,6 +15,8 @@ use crate::header::{HeaderValue, SET_COOKIE};
 use crate::{Body, Error};
 use bytes::Bytes;
 use http::{HeaderMap, Request, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use tower::Service;
 use url::Url;
 
@@ -22,11 +26,36 @@ pub trait CookieStore: Send + Sync {
     fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &url::Url);
     /// Get any Cookie values in the store for `url`
     fn cookies(&self, url: &url::Url) -> Option<HeaderValue>;
+
+    /// Return every cookie currently stored that would be sent to `url`.
+    ///
+    /// The default implementation returns an empty list.
+    fn matches(&self, _url: &url::Url) -> Vec<Cookie<'static>> {
+        Vec::new()
+    }
+
+    /// Remove the cookie named `name` that would be sent to `url`, if any.
+    ///
+    /// The default implementation does nothing.
+    fn remove(&self, _url: &url::Url, _name: &str) {}
+
+    /// Remove every cookie from the store.
+    ///
+    /// The default implementation does nothing.
+    fn clear(&self) {}
 }
 
 /// A single HTTP cookie.
 pub struct Cookie<'a>(cookie_crate::Cookie<'a>);
 
+/// A cryptographic key used by [`SignedJar`] and [`PrivateJar`] to
+/// authenticate, or authenticate-and-encrypt, cookie values.
+pub use cookie_crate::Key;
+
+/// The `SameSite` cookie attribute, re-exported for use with
+/// [`JarConfig::same_site`].
+pub use cookie_crate::SameSite;
+
 /// A good default `CookieStore` implementation.
 ///
 /// This is the implementation used when simply calling `cookie_store(true)`.
@@ -37,10 +66,74 @@ pub struct Cookie<'a>(cookie_crate::Cookie<'a>);
 /// manipulate it between requests, you may refer to the
 /// [reqwest_cookie_store crate](https://crates.io/crates/reqwest_cookie_store).
 #[derive(Debug, Default)]
-pub struct Jar(RwLock<cookie_store::CookieStore>);
+pub struct Jar {
+    store: RwLock<cookie_store::CookieStore>,
+    config: JarConfig,
+}
 impl Clone for Jar{
     fn clone(&self) -> Self {
-        Self(RwLock::new(self.0.write().unwrap().clone()))
+        Self {
+            store: RwLock::new(self.store.write().unwrap().clone()),
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// Default attributes applied to cookies added directly via
+/// [`Jar::add_cookie_str`]/[`Jar::add_cookie`]; unset by `Jar::default()`,
+/// [`Jar::builder()`] starts from `Path=/` and `SameSite=Strict`.
+#[derive(Debug, Clone, Default)]
+pub struct JarConfig {
+    path: Option<String>,
+    same_site: Option<cookie_crate::SameSite>,
+    secure: Option<bool>,
+}
+
+impl JarConfig {
+    /// Default `Path` applied when an added cookie doesn't specify one.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Default `SameSite` applied when an added cookie doesn't specify one.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Default `Secure` flag applied when an added cookie doesn't specify
+    /// one.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = Some(secure);
+        self
+    }
+
+    /// Build the [`Jar`] that will apply these defaults.
+    pub fn build(self) -> Jar {
+        Jar {
+            store: RwLock::new(cookie_store::CookieStore::default()),
+            config: self,
+        }
+    }
+
+    fn apply(&self, mut cookie: cookie_crate::Cookie<'static>) -> cookie_crate::Cookie<'static> {
+        if cookie.path().is_none() {
+            if let Some(path) = self.path.clone() {
+                cookie.set_path(path);
+            }
+        }
+        if cookie.same_site().is_none() {
+            if let Some(same_site) = self.same_site {
+                cookie.set_same_site(same_site);
+            }
+        }
+        if cookie.secure().is_none() {
+            if let Some(secure) = self.secure {
+                cookie.set_secure(secure);
+            }
+        }
+        cookie
     }
 }
 // ===== impl Cookie =====
@@ -134,6 +227,140 @@ impl<'a> fmt::Display for CookieParseError {
 
 impl std::error::Error for CookieParseError {}
 
+/// An on-disk representation of a single persistent cookie, used by
+/// [`Jar::save_json`]/[`Jar::load_json`]; session cookies (no `expires`)
+/// are dropped rather than represented.
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializableCookie {
+    name: String,
+    value: String,
+    domain: String,
+    host_only: bool,
+    path: String,
+    /// Seconds since the Unix epoch.
+    expires: u64,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SerializableSameSite>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum SerializableSameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SerializableCookie {
+    fn from_cookie(cookie: &cookie_store::Cookie<'_>) -> Option<Self> {
+        // The store's own `expires` (from `Max-Age` or `Expires`) and
+        // `domain` (the request host for host-only cookies), not the raw
+        // `Set-Cookie` attributes.
+        let expires = match cookie.expires {
+            cookie_store::CookieExpiration::AtUtc(dt) => {
+                SystemTime::from(dt)
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .ok()?
+                    .as_secs()
+            }
+            // Session cookies carry no `expires` and aren't persisted.
+            cookie_store::CookieExpiration::SessionEnd => return None,
+        };
+
+        Some(SerializableCookie {
+            name: cookie.name().to_owned(),
+            value: cookie.value().to_owned(),
+            domain: String::from(&cookie.domain),
+            host_only: matches!(cookie.domain, cookie_store::CookieDomain::HostOnly(_)),
+            path: cookie.path().unwrap_or("/").to_owned(),
+            expires,
+            secure: cookie.secure().unwrap_or(false),
+            http_only: cookie.http_only().unwrap_or(false),
+            same_site: match cookie.same_site() {
+                Some(cookie_crate::SameSite::Strict) => Some(SerializableSameSite::Strict),
+                Some(cookie_crate::SameSite::Lax) => Some(SerializableSameSite::Lax),
+                Some(cookie_crate::SameSite::None) => Some(SerializableSameSite::None),
+                None => None,
+            },
+        })
+    }
+
+    /// Errors if `expires` doesn't fit a valid `OffsetDateTime`.
+    fn into_cookie(self) -> crate::Result<cookie_crate::Cookie<'static>> {
+        let seconds =
+            i64::try_from(self.expires).map_err(|_| Error::new(Kind::Body, Some("cookie expiry out of range")))?;
+        let expires = cookie_crate::time::OffsetDateTime::UNIX_EPOCH
+            .checked_add(cookie_crate::time::Duration::seconds(seconds))
+            .ok_or_else(|| Error::new(Kind::Body, Some("cookie expiry out of range")))?;
+
+        let mut builder = cookie_crate::Cookie::build((self.name, self.value))
+            .path(self.path.clone())
+            .secure(self.secure)
+            .http_only(self.http_only)
+            .expires(expires);
+
+        // A host-only cookie never carried an explicit `Domain` attribute;
+        // setting one here would turn it into a domain cookie on reload,
+        // so only set it back for cookies that actually had one.
+        if !self.host_only {
+            builder = builder.domain(self.domain.clone());
+        }
+
+        if let Some(same_site) = self.same_site {
+            builder = builder.same_site(match same_site {
+                SerializableSameSite::Strict => cookie_crate::SameSite::Strict,
+                SerializableSameSite::Lax => cookie_crate::SameSite::Lax,
+                SerializableSameSite::None => cookie_crate::SameSite::None,
+            });
+        }
+
+        Ok(builder.build())
+    }
+
+    /// The request URL passed to `cookie_store::CookieStore::insert_raw`
+    /// when loading this cookie back in: its host is always the real
+    /// host the cookie was scoped to (whether host-only or domain-wide),
+    /// since `domain`/`host_only` are read straight from the store.
+    fn request_url(&self) -> Url {
+        let host = self.domain.trim_start_matches('.');
+        format!("https://{host}{}", self.path)
+            .parse()
+            .unwrap_or_else(|_| Url::parse("https://localhost/").expect("valid fallback url"))
+    }
+}
+
+/// Every cookie in `store` that would be sent to `url`; shared by [`Jar`]
+/// and [`CryptoJar`].
+fn store_matches(store: &RwLock<cookie_store::CookieStore>, url: &url::Url) -> Vec<Cookie<'static>> {
+    store
+        .read()
+        .unwrap()
+        .matches(url)
+        .into_iter()
+        .map(|c| Cookie(c.clone().into_owned()))
+        .collect()
+}
+
+/// Remove the cookie named `name` that would be sent to `url`, if any.
+fn store_remove(store: &RwLock<cookie_store::CookieStore>, url: &url::Url, name: &str) {
+    let mut store = store.write().unwrap();
+    let targets: Vec<(String, String)> = store
+        .matches(url)
+        .into_iter()
+        .filter(|c| c.name() == name)
+        .map(|c| (String::from(&c.domain), String::from(&c.path)))
+        .collect();
+
+    for (domain, path) in targets {
+        store.remove(&domain, &path, name);
+    }
+}
+
+/// Remove every cookie from `store`.
+fn store_clear(store: &RwLock<cookie_store::CookieStore>) {
+    *store.write().unwrap() = cookie_store::CookieStore::default();
+}
+
 // ===== impl Jar =====
 
 impl Jar {
@@ -156,9 +383,100 @@ impl Jar {
         let cookies = cookie_crate::Cookie::parse(cookie)
             .ok()
             .map(|c| c.into_owned())
+            .map(|c| self.config.apply(c))
             .into_iter();
-        self.0.write().unwrap().store_response_cookies(cookies, url);
+        self.store.write().unwrap().store_response_cookies(cookies, url);
+    }
+
+    /// Start configuring default attributes (`Path`, `SameSite`, `Secure`)
+    /// for cookies added directly via [`Jar::add_cookie_str`]/[`Jar::add_cookie`],
+    /// starting from Rocket's `Path=/` and `SameSite=Strict`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::cookie::{Jar, SameSite};
+    ///
+    /// let jar = Jar::builder().same_site(SameSite::Lax).build();
+    /// ```
+    pub fn builder() -> JarConfig {
+        JarConfig {
+            path: Some("/".to_owned()),
+            same_site: Some(SameSite::Strict),
+            secure: None,
+        }
     }
+
+    /// Add an already-parsed cookie to this jar, applying this jar's
+    /// configured defaults (see [`Jar::builder`]) for any attribute the
+    /// cookie doesn't specify.
+    pub fn add_cookie(&self, cookie: Cookie<'static>, url: &url::Url) {
+        let cookie = self.config.apply(cookie.0);
+        self.store
+            .write()
+            .unwrap()
+            .store_response_cookies(std::iter::once(cookie), url);
+    }
+
+    /// Serialize every non-expired, persistent cookie in this jar as JSON,
+    /// one object per line; session cookies are skipped. Only takes the
+    /// jar's read lock, so it won't deadlock a `Client` sharing this jar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::{cookie::Jar, Url};
+    ///
+    /// let url = "https://yolo.local".parse::<Url>().unwrap();
+    /// let jar = Jar::default();
+    /// jar.add_cookie_str("foo=bar; Domain=yolo.local; Max-Age=60", &url);
+    ///
+    /// let mut buf = Vec::new();
+    /// jar.save_json(&mut buf).unwrap();
+    /// ```
+    pub fn save_json<W: Write>(&self, writer: &mut W) -> crate::Result<()> {
+        let store = self.store.read().unwrap();
+        for cookie in store.iter_unexpired() {
+            let Some(record) = SerializableCookie::from_cookie(cookie) else {
+                continue;
+            };
+            serde_json::to_writer(&mut *writer, &record).map_err(crate::error::builder)?;
+            writer.write_all(b"\n").map_err(crate::error::builder)?;
+        }
+        Ok(())
+    }
+
+    /// Load a jar previously written by [`Jar::save_json`]; constructs a
+    /// brand new `Jar` rather than mutating one already in use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::cookie::Jar;
+    /// use std::io::Cursor;
+    ///
+    /// let mut data = Cursor::new(Vec::new());
+    /// let jar = Jar::load_json(&mut data).unwrap();
+    /// ```
+    pub fn load_json<R: BufRead>(reader: &mut R) -> crate::Result<Self> {
+        let jar = Jar::default();
+        {
+            let mut store = jar.store.write().unwrap();
+            for line in reader.lines() {
+                let line = line.map_err(crate::error::builder)?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let record: SerializableCookie =
+                    serde_json::from_str(&line).map_err(crate::error::builder)?;
+                let url = record.request_url();
+                let cookie = record.into_cookie()?;
+                store.insert_raw(&cookie, &url).map_err(crate::error::builder)?;
+            }
+        }
+        Ok(jar)
+    }
+
     /// private
     async fn extract_response_cookie_headers<'a>(
         &self,
@@ -198,12 +516,12 @@ impl CookieStore for Jar {
         let iter =
             cookie_headers.filter_map(|val| Cookie::parse(val).map(|c| c.0.into_owned()).ok());
 
-        self.0.write().unwrap().store_response_cookies(iter, url);
+        self.store.write().unwrap().store_response_cookies(iter, url);
     }
 
     fn cookies(&self, url: &url::Url) -> Option<HeaderValue> {
         let s = self
-            .0
+            .store
             .read()
             .unwrap()
             .get_request_values(url)
@@ -217,7 +535,427 @@ impl CookieStore for Jar {
 
         HeaderValue::from_maybe_shared(Bytes::from(s)).ok()
     }
+
+    fn matches(&self, url: &url::Url) -> Vec<Cookie<'static>> {
+        store_matches(&self.store, url)
+    }
+
+    fn remove(&self, url: &url::Url, name: &str) {
+        store_remove(&self.store, url, name);
+    }
+
+    fn clear(&self) {
+        store_clear(&self.store);
+    }
+}
+
+#[cfg(test)]
+mod jar_json_tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_json_round_trip_preserves_host_only_cookies() {
+        let jar = Jar::default();
+        let url: Url = "https://example.com/".parse().unwrap();
+        // Host-only: no explicit `Domain` attribute.
+        jar.add_cookie_str("host=only; Max-Age=3600", &url);
+        // Domain cookie: explicit `Domain` attribute, visible to subdomains.
+        jar.add_cookie_str("wide=cookie; Domain=example.com; Max-Age=3600", &url);
+
+        let mut buf = Vec::new();
+        jar.save_json(&mut buf).unwrap();
+
+        let loaded = Jar::load_json(&mut &buf[..]).unwrap();
+
+        let same_host = loaded.cookies(&url).unwrap();
+        let same_host = same_host.to_str().unwrap();
+        assert!(same_host.contains("host=only"));
+        assert!(same_host.contains("wide=cookie"));
+
+        // The host-only cookie must not leak to a different subdomain;
+        // the domain cookie should still be sent there.
+        let subdomain: Url = "https://sub.example.com/".parse().unwrap();
+        let subdomain_cookies = loaded
+            .cookies(&subdomain)
+            .and_then(|v| v.to_str().map(str::to_owned).ok())
+            .unwrap_or_default();
+        assert!(!subdomain_cookies.contains("host=only"));
+        assert!(subdomain_cookies.contains("wide=cookie"));
+    }
+
+    #[test]
+    fn save_json_only_takes_a_read_lock() {
+        use std::sync::mpsc;
+        use std::thread;
+
+        let jar = Arc::new(Jar::default());
+        let url: Url = "https://example.com/".parse().unwrap();
+        jar.add_cookie_str("a=b; Max-Age=3600", &url);
+
+        // Hold a read guard on another thread for the duration of the
+        // call below; if `save_json` ever took the write lock instead,
+        // it would block on this guard and the test would hang.
+        let (ready_tx, ready_rx) = mpsc::channel::<()>();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let reader_jar = jar.clone();
+        let reader = thread::spawn(move || {
+            let _guard = reader_jar.store.read().unwrap();
+            ready_tx.send(()).unwrap();
+            let _ = release_rx.recv();
+        });
+
+        ready_rx.recv().unwrap();
+        let mut buf = Vec::new();
+        jar.save_json(&mut buf).unwrap();
+
+        release_tx.send(()).unwrap();
+        reader.join().unwrap();
+    }
+
+    #[test]
+    fn load_json_builds_a_fresh_jar() {
+        let existing = Jar::default();
+        let url: Url = "https://example.com/".parse().unwrap();
+        existing.add_cookie_str("a=b; Max-Age=3600", &url);
+
+        let mut buf = Vec::new();
+        existing.save_json(&mut buf).unwrap();
+
+        let loaded = Jar::load_json(&mut &buf[..]).unwrap();
+
+        // `existing` wasn't mutated or locked out by `load_json`.
+        assert!(existing.cookies(&url).is_some());
+        assert!(loaded.cookies(&url).is_some());
+    }
 }
+
+// ===== impl SignedJar / PrivateJar =====
+
+#[derive(Debug, Clone, Copy)]
+enum CryptoMode {
+    Signed,
+    Private,
+}
+
+/// A [`CookieStore`] that can also store and recall a plaintext value
+/// directly, bypassing the sign/verify step; implemented by [`SignedJar`]
+/// and [`PrivateJar`], used by [`SessionService`] to fold in its session blob.
+trait CryptoCookieJar: CookieStore {
+    fn insert_plain(&self, name: &str, value: &str, url: &Url);
+    fn get_plain(&self, name: &str, url: &Url) -> Option<String>;
+}
+
+/// Shared storage and crypto logic behind [`SignedJar`] and [`PrivateJar`];
+/// `session_store` holds only the blob [`SessionService`] folds in via
+/// `insert_plain`/`get_plain`, kept out of `store`'s `matches`/`remove`/`clear`.
+struct CryptoJar {
+    key: Key,
+    mode: CryptoMode,
+    store: RwLock<cookie_store::CookieStore>,
+    session_store: RwLock<cookie_store::CookieStore>,
+}
+
+impl fmt::Debug for CryptoJar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CryptoJar")
+            .field("mode", &self.mode)
+            .field("store", &self.store)
+            .finish()
+    }
+}
+
+impl Clone for CryptoJar {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            mode: self.mode,
+            store: RwLock::new(self.store.write().unwrap().clone()),
+            session_store: RwLock::new(self.session_store.write().unwrap().clone()),
+        }
+    }
+}
+
+impl CryptoJar {
+    fn new(key: Key, mode: CryptoMode) -> Self {
+        Self {
+            key,
+            mode,
+            store: RwLock::new(cookie_store::CookieStore::default()),
+            session_store: RwLock::new(cookie_store::CookieStore::default()),
+        }
+    }
+
+    /// Insert a plaintext cookie into `session_store`, without verifying a
+    /// signature; always scoped to `Path=/` so one session is shared
+    /// host-wide rather than split per request path.
+    fn insert_plain(&self, name: &str, value: &str, url: &Url) {
+        let cookie = cookie_crate::Cookie::build((name.to_owned(), value.to_owned()))
+            .path("/")
+            .build();
+        self.session_store
+            .write()
+            .unwrap()
+            .store_response_cookies(std::iter::once(cookie), url);
+    }
+
+    /// Read back a plaintext cookie previously stored with `insert_plain`,
+    /// bypassing the sign/encrypt step `cookies()` applies for the
+    /// outgoing header.
+    fn get_plain(&self, name: &str, url: &Url) -> Option<String> {
+        self.session_store
+            .read()
+            .unwrap()
+            .get_request_values(url)
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.to_owned())
+    }
+
+    /// Verify (and decrypt, for `Private`) one incoming `Set-Cookie` value;
+    /// returns `None` if it fails the HMAC/AEAD check.
+    fn decode(
+        &self,
+        cookie: cookie_crate::Cookie<'static>,
+    ) -> Option<cookie_crate::Cookie<'static>> {
+        let name = cookie.name().to_owned();
+        let mut verifier = cookie_crate::CookieJar::new();
+        verifier.add_original(cookie.clone());
+        let value = match self.mode {
+            CryptoMode::Signed => verifier.signed(&self.key).get(&name)?.value().to_owned(),
+            CryptoMode::Private => verifier.private(&self.key).get(&name)?.value().to_owned(),
+        };
+        let mut decoded = cookie;
+        decoded.set_value(value);
+        Some(decoded)
+    }
+
+    /// Sign (or encrypt) one stored, plaintext cookie value for the
+    /// outgoing `Cookie` header.
+    fn encode(&self, name: &str, value: &str) -> String {
+        let mut jar = cookie_crate::CookieJar::new();
+        let plain = cookie_crate::Cookie::new(name.to_owned(), value.to_owned());
+        match self.mode {
+            CryptoMode::Signed => jar.signed_mut(&self.key).add(plain),
+            CryptoMode::Private => jar.private_mut(&self.key).add(plain),
+        }
+        jar.get(name).map(|c| c.value().to_owned()).unwrap_or_default()
+    }
+
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &url::Url) {
+        let decoded = cookie_headers
+            .filter_map(|val| Cookie::parse(val).ok())
+            .filter_map(|c| self.decode(c.0.into_owned()));
+
+        self.store.write().unwrap().store_response_cookies(decoded, url);
+    }
+
+    /// Includes the blob in `session_store`, signed or encrypted like any
+    /// other stored cookie.
+    fn cookies(&self, url: &url::Url) -> Option<HeaderValue> {
+        let store = self.store.read().unwrap();
+        let session_store = self.session_store.read().unwrap();
+        let s = store
+            .get_request_values(url)
+            .chain(session_store.get_request_values(url))
+            .map(|(name, value)| format!("{name}={}", self.encode(name, value)))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if s.is_empty() {
+            return None;
+        }
+
+        HeaderValue::from_maybe_shared(Bytes::from(s)).ok()
+    }
+
+    /// As with [`Jar::matches`], this returns the jar's own plaintext
+    /// values, not the signed/encrypted wire representation.
+    fn matches(&self, url: &url::Url) -> Vec<Cookie<'static>> {
+        store_matches(&self.store, url)
+    }
+
+    fn remove(&self, url: &url::Url, name: &str) {
+        store_remove(&self.store, url, name);
+    }
+
+    fn clear(&self) {
+        store_clear(&self.store);
+    }
+}
+
+/// A `CookieStore` that authenticates every cookie value with an HMAC tag,
+/// backed by a [`Key`]; tampered or unsigned incoming values are silently
+/// dropped rather than stored.
+#[derive(Debug)]
+pub struct SignedJar(CryptoJar);
+
+impl Clone for SignedJar {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl SignedJar {
+    /// Create a jar that signs and verifies cookies with `key`.
+    pub fn new(key: Key) -> Self {
+        Self(CryptoJar::new(key, CryptoMode::Signed))
+    }
+}
+
+impl CryptoCookieJar for SignedJar {
+    fn insert_plain(&self, name: &str, value: &str, url: &Url) {
+        self.0.insert_plain(name, value, url);
+    }
+
+    fn get_plain(&self, name: &str, url: &Url) -> Option<String> {
+        self.0.get_plain(name, url)
+    }
+}
+
+impl CookieStore for SignedJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &url::Url) {
+        self.0.set_cookies(cookie_headers, url);
+    }
+
+    fn cookies(&self, url: &url::Url) -> Option<HeaderValue> {
+        self.0.cookies(url)
+    }
+
+    fn matches(&self, url: &url::Url) -> Vec<Cookie<'static>> {
+        self.0.matches(url)
+    }
+
+    fn remove(&self, url: &url::Url, name: &str) {
+        self.0.remove(url, name);
+    }
+
+    fn clear(&self) {
+        self.0.clear();
+    }
+}
+
+/// A `CookieStore` that authenticates and encrypts every cookie value
+/// (AEAD), backed by a [`Key`]; unlike [`SignedJar`], only this jar can
+/// read the stored values, and tampered/unencrypted ones are dropped.
+#[derive(Debug)]
+pub struct PrivateJar(CryptoJar);
+
+impl Clone for PrivateJar {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl PrivateJar {
+    /// Create a jar that encrypts and decrypts cookies with `key`.
+    pub fn new(key: Key) -> Self {
+        Self(CryptoJar::new(key, CryptoMode::Private))
+    }
+}
+
+impl CryptoCookieJar for PrivateJar {
+    fn insert_plain(&self, name: &str, value: &str, url: &Url) {
+        self.0.insert_plain(name, value, url);
+    }
+
+    fn get_plain(&self, name: &str, url: &Url) -> Option<String> {
+        self.0.get_plain(name, url)
+    }
+}
+
+impl CookieStore for PrivateJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &url::Url) {
+        self.0.set_cookies(cookie_headers, url);
+    }
+
+    fn cookies(&self, url: &url::Url) -> Option<HeaderValue> {
+        self.0.cookies(url)
+    }
+
+    fn matches(&self, url: &url::Url) -> Vec<Cookie<'static>> {
+        self.0.matches(url)
+    }
+
+    fn remove(&self, url: &url::Url, name: &str) {
+        self.0.remove(url, name);
+    }
+
+    fn clear(&self) {
+        self.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod crypto_jar_tests {
+    use super::*;
+
+    /// Sign/encrypt `name`/`value` the way a server holding `key` would,
+    /// so tests can simulate a `Set-Cookie` header without going through
+    /// a `SignedJar`/`PrivateJar` of their own.
+    fn wire_value(key: &Key, mode: CryptoMode, name: &str, value: &str) -> String {
+        let mut jar = cookie_crate::CookieJar::new();
+        let plain = cookie_crate::Cookie::new(name.to_owned(), value.to_owned());
+        match mode {
+            CryptoMode::Signed => jar.signed_mut(key).add(plain),
+            CryptoMode::Private => jar.private_mut(key).add(plain),
+        }
+        jar.get(name).unwrap().value().to_owned()
+    }
+
+    #[test]
+    fn signed_jar_round_trips_a_verified_cookie() {
+        let key = Key::generate();
+        let wire = wire_value(&key, CryptoMode::Signed, "session", "abc123");
+        let header = HeaderValue::from_str(&format!("session={wire}")).unwrap();
+        let url: Url = "https://example.com/".parse().unwrap();
+
+        let jar = SignedJar::new(key);
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        let stored = jar.matches(&url);
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].name(), "session");
+        assert_eq!(stored[0].value(), "abc123");
+    }
+
+    #[test]
+    fn signed_jar_drops_a_tampered_cookie() {
+        let key = Key::generate();
+        let mut wire = wire_value(&key, CryptoMode::Signed, "session", "abc123");
+        // Change the signed value so its tag no longer verifies.
+        wire.push('x');
+        let header = HeaderValue::from_str(&format!("session={wire}")).unwrap();
+        let url: Url = "https://example.com/".parse().unwrap();
+
+        let jar = SignedJar::new(key);
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        assert!(jar.matches(&url).is_empty());
+        assert!(jar.cookies(&url).is_none());
+    }
+
+    #[test]
+    fn private_jar_round_trips_and_rejects_tampering() {
+        let key = Key::generate();
+        let url: Url = "https://example.com/".parse().unwrap();
+
+        let good_wire = wire_value(&key, CryptoMode::Private, "session", "top-secret");
+        let good_header = HeaderValue::from_str(&format!("session={good_wire}")).unwrap();
+        let jar = PrivateJar::new(key.clone());
+        jar.set_cookies(&mut std::iter::once(&good_header), &url);
+        let stored = jar.matches(&url);
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].value(), "top-secret");
+
+        let mut bad_wire = good_wire;
+        bad_wire.push('x');
+        let bad_header = HeaderValue::from_str(&format!("session={bad_wire}")).unwrap();
+        let tampered_jar = PrivateJar::new(key);
+        tampered_jar.set_cookies(&mut std::iter::once(&bad_header), &url);
+
+        assert!(tampered_jar.matches(&url).is_empty());
+    }
+}
+
 /// a service enables an async client or h3 client to manage cookies
 #[derive(Debug)]
 pub struct CookiesEnabledService<S>
@@ -306,3 +1044,144 @@ impl<
         Self { store: Arc::new(self.store.as_ref().clone()), inner_service: self.inner_service.clone() }
     }
 }
+
+/// Name of the single cookie a [`SessionService`] folds its [`Session`] map into.
+const SESSION_COOKIE_NAME: &str = "__reqwest_session";
+
+/// A typed, cookie-backed session store, accumulated across requests made
+/// through a [`SessionService`]; values are JSON-encoded into a single map.
+#[derive(Debug, Default)]
+pub struct Session(RwLock<HashMap<String, String>>);
+
+impl Session {
+    /// Deserialize the value stored at `key`, if any.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> crate::Result<Option<T>> {
+        match self.0.read().unwrap().get(key) {
+            Some(raw) => serde_json::from_str(raw)
+                .map(Some)
+                .map_err(crate::error::builder),
+            None => Ok(None),
+        }
+    }
+
+    /// Serialize `value` and store it at `key`.
+    pub fn set<T: Serialize>(&self, key: &str, value: T) -> crate::Result<()> {
+        let raw = serde_json::to_string(&value).map_err(crate::error::builder)?;
+        self.0.write().unwrap().insert(key.to_owned(), raw);
+        Ok(())
+    }
+
+    /// Remove the value stored at `key`, if any.
+    pub fn remove(&self, key: &str) {
+        self.0.write().unwrap().remove(key);
+    }
+
+    /// Remove every value from the session.
+    pub fn clear(&self) {
+        self.0.write().unwrap().clear();
+    }
+
+    fn to_cookie_value(&self) -> crate::Result<String> {
+        serde_json::to_string(&*self.0.read().unwrap()).map_err(crate::error::builder)
+    }
+
+    fn load_from_cookie_value(&self, value: &str) {
+        if let Ok(map) = serde_json::from_str::<HashMap<String, String>>(value) {
+            *self.0.write().unwrap() = map;
+        }
+    }
+}
+
+/// A service that layers a typed [`Session`] on top of an inner service,
+/// folding it into a single cookie via `store` (a [`SignedJar`] or
+/// [`PrivateJar`]) before every request and parsing it back out after.
+#[derive(Debug)]
+pub struct SessionService<S, J> {
+    store: Arc<J>,
+    session: Arc<Session>,
+    inner_service: S,
+}
+
+impl<S, J: CryptoCookieJar> SessionService<S, J> {
+    /// Wrap `service`, keeping session state in `session` and signing or
+    /// encrypting it via `store`.
+    pub fn new(service: S, store: Arc<J>, session: Arc<Session>) -> Self {
+        Self {
+            store,
+            session,
+            inner_service: service,
+        }
+    }
+
+    /// The shared session handle, to read and write from outside the
+    /// request path.
+    pub fn session(&self) -> Arc<Session> {
+        self.session.clone()
+    }
+}
+
+impl<
+        S: Service<
+            Request<Body>,
+            Response = http::Response<ResponseBody>,
+            Error = crate::error::Error,
+            Future: Sync + Send + 'static,
+        > + Clone,
+        J: CryptoCookieJar + Send + Sync + 'static,
+    > Service<Request<Body>> for SessionService<S, J>
+{
+    type Response = Response<ResponseBody>;
+
+    type Error = Error;
+
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send + Sync>>;
+
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
+        let url = Url::parse(req.uri().to_string().as_str()).expect("invalid URL");
+
+        // Fold the current session into its cookie before the inner
+        // store signs/encrypts everything for the outgoing header.
+        if let Ok(value) = self.session.to_cookie_value() {
+            self.store.insert_plain(SESSION_COOKIE_NAME, &value, &url);
+        }
+
+        let headers = req.headers_mut();
+        crate::util::add_cookie_header(headers, self.store.as_ref(), &url);
+
+        let inner_response_future = self.inner_service.call(req);
+
+        let store = self.store.clone();
+        let session = self.session.clone();
+        Box::pin(async move {
+            let response = inner_response_future.await;
+            if let Ok(res) = response {
+                store.set_cookies(&mut res.headers().get_all(SET_COOKIE).iter(), &url);
+                if let Some(value) = store.get_plain(SESSION_COOKIE_NAME, &url) {
+                    session.load_from_cookie_value(&value);
+                }
+                return Ok(res);
+            }
+            Err(Error::new(
+                Kind::Body,
+                Some("error extract response in session service"),
+            ))
+        })
+    }
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner_service.poll_ready(cx)
+    }
+}
+
+impl<S: Clone, J> Clone for SessionService<S, J> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            session: self.session.clone(),
+            inner_service: self.inner_service.clone(),
+        }
+    }
+}